@@ -5,8 +5,11 @@ use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use ethabi::{Token, Uint as ethUint};
+use ethabi::ParamType;
 use eyre::{eyre, Context};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tiny_keccak::{Hasher, Keccak};
 
 use crate::types::address::Address;
 use crate::types::eth_abi::Encode;
@@ -23,8 +26,6 @@ use crate::types::token::Amount;
     Hash,
     PartialEq,
     Eq,
-    PartialOrd,
-    Ord,
     Serialize,
     Deserialize,
     BorshSerialize,
@@ -33,6 +34,21 @@ use crate::types::token::Amount;
 )]
 pub struct Uint(pub [u64; 4]);
 
+// NB: `[u64; 4]` is little-endian limb order (least-significant limb first),
+// so the derived lexicographic ordering compares the wrong limb first and is
+// not numeric order. Delegate to `ethUint`, which compares numerically.
+impl PartialOrd for Uint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Uint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        ethUint::from(self).cmp(&ethUint::from(other))
+    }
+}
+
 impl Uint {
     /// Convert to a little endian byte representation of
     /// a uint256.
@@ -41,6 +57,93 @@ impl Uint {
         ethUint::from(self).to_little_endian(&mut bytes);
         bytes
     }
+
+    /// Convert to a big endian byte representation of a uint256.
+    pub fn to_big_endian(self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        ethUint::from(self).to_big_endian(&mut bytes);
+        bytes
+    }
+
+    /// Interpret 32 little endian bytes as a uint256.
+    pub fn from_little_endian(bytes: &[u8; 32]) -> Self {
+        ethUint::from_little_endian(bytes).into()
+    }
+
+    /// Interpret 32 big endian bytes as a uint256.
+    pub fn from_big_endian(bytes: &[u8; 32]) -> Self {
+        ethUint::from_big_endian(bytes).into()
+    }
+
+    /// Checked addition. Returns `None` on overflow of the 256-bit range.
+    pub fn checked_add(&self, rhs: &Uint) -> Option<Uint> {
+        ethUint::from(self).checked_add(rhs.into()).map(Uint::from)
+    }
+
+    /// Checked subtraction. Returns `None` if `rhs` is larger than `self`.
+    pub fn checked_sub(&self, rhs: &Uint) -> Option<Uint> {
+        ethUint::from(self).checked_sub(rhs.into()).map(Uint::from)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow of the 256-bit
+    /// range.
+    pub fn checked_mul(&self, rhs: &Uint) -> Option<Uint> {
+        ethUint::from(self).checked_mul(rhs.into()).map(Uint::from)
+    }
+
+    /// Wrapping addition, returning the result and whether the 256-bit range
+    /// overflowed.
+    pub fn overflowing_add(&self, rhs: &Uint) -> (Uint, bool) {
+        let (result, overflow) =
+            ethUint::from(self).overflowing_add(rhs.into());
+        (result.into(), overflow)
+    }
+
+    /// Saturating addition, clamping to the maximum uint256 on overflow.
+    pub fn saturating_add(&self, rhs: &Uint) -> Uint {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { ethUint::MAX.into() } else { result }
+    }
+
+    /// Return the next value, as used to advance a monotonically increasing
+    /// `nonce`. Returns `None` only on overflow of the 256-bit range.
+    pub fn increment(&self) -> Option<Uint> {
+        self.checked_add(&Uint::from(1u64))
+    }
+}
+
+impl Display for Uint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", ethUint::from(self))
+    }
+}
+
+impl FromStr for Uint {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ethUint::from_dec_str(s)
+            .map(Uint::from)
+            .wrap_err_with(|| eyre!("couldn't parse uint256 {}", s))
+    }
+}
+
+impl From<Amount> for Uint {
+    fn from(amount: Amount) -> Self {
+        Uint::from(u64::from(amount))
+    }
+}
+
+impl TryFrom<Uint> for Amount {
+    type Error = eyre::Error;
+
+    fn try_from(value: Uint) -> Result<Self, Self::Error> {
+        let uint = ethUint::from(value);
+        if uint.bits() > 64 {
+            return Err(eyre!("uint256 {} overflows Amount", uint));
+        }
+        Ok(Amount::from(uint.low_u64()))
+    }
 }
 
 impl Encode<1> for Uint {
@@ -101,6 +204,134 @@ impl EthAddress {
     pub fn to_canonical(&self) -> String {
         format!("{:?}", ethabi::ethereum_types::Address::from(&self.0))
     }
+
+    /// The [EIP-55] mixed-case checksummed representation of this address,
+    /// prefixed by '0x'. e.g. "0x6B175474E89094C44Da98b954EedeAC495271d0F".
+    ///
+    /// The casing of each hex letter is derived from the `keccak256` hash of
+    /// the lower case hex digits, which lets a reader catch mistyped
+    /// characters without a separate checksum field.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    pub fn to_checksum(&self) -> String {
+        let lower = self.to_canonical();
+        // the 40 lower case hex digits, without the '0x' prefix
+        let hex = &lower[2..];
+        let hash = keccak256(hex.as_bytes());
+        let mut checksummed = String::with_capacity(lower.len());
+        checksummed.push_str("0x");
+        for (i, c) in hex.chars().enumerate() {
+            if c.is_ascii_digit() {
+                checksummed.push(c);
+            } else {
+                let byte = hash[i / 2];
+                let nibble =
+                    if i % 2 == 0 { byte >> 4 } else { byte & 0x0f };
+                if nibble >= 8 {
+                    checksummed.push(c.to_ascii_uppercase());
+                } else {
+                    checksummed.push(c);
+                }
+            }
+        }
+        checksummed
+    }
+
+    /// Parse an [`EthAddress`] from a hex-encoded string, rejecting a
+    /// mixed-case input whose [EIP-55] checksum does not match. An all
+    /// lower case or all upper case input carries no checksum and is accepted
+    /// as by [`FromStr`], which is how addresses have always been parsed.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    pub fn from_checksum_str(s: &str) -> Result<Self, eyre::Error> {
+        let addr = Self::from_str(s)?;
+        let hex = s.strip_prefix("0x").unwrap_or(s);
+        let has_lower = hex.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = hex.chars().any(|c| c.is_ascii_uppercase());
+        if has_lower && has_upper && addr.to_checksum() != format!("0x{}", hex)
+        {
+            return Err(eyre!(
+                "EIP-55 checksum mismatch for Ethereum address {}",
+                s
+            ));
+        }
+        Ok(addr)
+    }
+
+    /// Recover the [`EthAddress`] that produced `signature` over `msg`.
+    ///
+    /// This lets the ledger check that the holder of an Ethereum private key
+    /// authorized a Namada-side action (e.g. claiming wrapped assets) by
+    /// signing a challenge off-chain, without a corresponding on-chain event.
+    ///
+    /// `signature` is the 65-byte `r || s || v` encoding produced by an
+    /// Ethereum wallet's `personal_sign` over `msg`. The digest is the
+    /// [EIP-191] hash
+    /// `keccak256("\x19Ethereum Signed Message:\n" || len(msg) || msg)`,
+    /// using the *actual* byte length of `msg` — this is exactly what a
+    /// standard wallet's `personal_sign` computes over the bytes it is asked
+    /// to sign, so `msg` must be the raw challenge the wallet signed, not a
+    /// pre-hashed digest of it. A caller that wants the holder of an
+    /// `EthAddress` to sign a fixed-size 32-byte challenge should pass that
+    /// challenge as `msg` directly, with no additional hashing. Malformed
+    /// signatures and high-`s` (malleable) signatures are rejected.
+    ///
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    pub fn recover(
+        msg: &[u8],
+        signature: &[u8; 65],
+    ) -> Result<Self, eyre::Error> {
+        use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+        use secp256k1::{Message, Secp256k1};
+
+        // `personal_sign` signs the keccak256 of
+        // `"\x19Ethereum Signed Message:\n" + len(msg) + msg`, where `len`
+        // is `msg`'s decimal byte length.
+        let prefix = format!("\x19Ethereum Signed Message:\n{}", msg.len());
+        let mut preimage = Vec::with_capacity(prefix.len() + msg.len());
+        preimage.extend_from_slice(prefix.as_bytes());
+        preimage.extend_from_slice(msg);
+        let digest = Message::from_slice(&keccak256(&preimage))
+            .wrap_err("invalid message digest")?;
+
+        // normalize the recovery id, tolerating the `27`/`28` offset used by
+        // `eth_sign` and `personal_sign`
+        let v = signature[64];
+        let recovery_id = if v >= 27 { v - 27 } else { v };
+        let recovery_id = RecoveryId::from_i32(recovery_id as i32)
+            .wrap_err("invalid signature recovery id")?;
+        let recoverable =
+            RecoverableSignature::from_compact(&signature[..64], recovery_id)
+                .wrap_err("malformed Ethereum signature")?;
+
+        // reject malleable signatures with a high `s` value
+        let standard = recoverable.to_standard();
+        let mut normalized = standard;
+        normalized.normalize_s();
+        if normalized != standard {
+            return Err(eyre!("malleable (high-s) Ethereum signature"));
+        }
+
+        let public_key = Secp256k1::verification_only()
+            .recover_ecdsa(&digest, &recoverable)
+            .wrap_err("failed to recover public key from signature")?;
+        // drop the leading `0x04` uncompressed-point tag
+        let uncompressed = public_key.serialize_uncompressed();
+        let hash = keccak256(&uncompressed[1..]);
+
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        Ok(Self(address))
+    }
+}
+
+/// Compute the `keccak256` digest of the given bytes.
+fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes);
+    hasher.finalize(&mut output);
+    output
 }
 
 impl Display for EthAddress {
@@ -168,62 +399,49 @@ pub enum EthereumEvent {
     /// from Ethereum to wrapped assets on Namada
     TransfersToNamada {
         /// Monotonically increasing nonce
-        #[allow(dead_code)]
         nonce: Uint,
         /// The batch of transfers
-        #[allow(dead_code)]
         transfers: Vec<TransferToNamada>,
     },
     /// A confirmation event that a batch of transfers have been made
     /// from Namada to Ethereum
     TransfersToEthereum {
         /// Monotonically increasing nonce
-        #[allow(dead_code)]
         nonce: Uint,
         /// The batch of transfers
-        #[allow(dead_code)]
         transfers: Vec<TransferToEthereum>,
     },
     /// Event indication that the validator set has been updated
     /// in the governance contract
     ValidatorSetUpdate {
         /// Monotonically increasing nonce
-        #[allow(dead_code)]
         nonce: Uint,
         /// Hash of the validators in the bridge contract
-        #[allow(dead_code)]
         bridge_validator_hash: KeccakHash,
         /// Hash of the validators in the governance contract
-        #[allow(dead_code)]
         governance_validator_hash: KeccakHash,
     },
     /// Event indication that a new smart contract has been
     /// deployed
     NewContract {
         /// Name of the contract
-        #[allow(dead_code)]
         name: String,
         /// Address of the contract on Ethereum
-        #[allow(dead_code)]
         address: EthAddress,
     },
     /// Event indicating that a smart contract has been updated
     UpgradedContract {
         /// Name of the contract
-        #[allow(dead_code)]
         name: String,
         /// Address of the contract on Ethereum
-        #[allow(dead_code)]
         address: EthAddress,
     },
     /// Event indication a new Ethereum based token has been whitelisted for
     /// transfer across the bridge
     UpdateBridgeWhitelist {
         /// Monotonically increasing nonce
-        #[allow(dead_code)]
         nonce: Uint,
         /// Tokens to be allowed to be transferred across the bridge
-        #[allow(dead_code)]
         whitelist: Vec<TokenWhitelist>,
     },
 }
@@ -234,6 +452,470 @@ impl EthereumEvent {
         let bytes = self.try_to_vec()?;
         Ok(Hash::sha256(bytes))
     }
+
+    /// Reconstruct an [`EthereumEvent`] from a raw Ethereum log.
+    ///
+    /// `topics[0]` is matched against the `keccak256` signature hash of each
+    /// known event to select the variant. Every event's leading `nonce` field
+    /// is treated as `indexed` and is ABI-decoded from `topics[1]`; the
+    /// remaining, non-indexed fields are ABI-decoded from `data`. This mirrors
+    /// how Solidity actually lays out a log: `topics[0]` is always the
+    /// selector, and a value-typed indexed parameter (our `nonce` is
+    /// `uint256`) lives in its own 32-byte topic rather than in `data`.
+    ///
+    /// The concrete signatures and indexed/non-indexed split below are this
+    /// module's own placeholder ABI, pinned by the tests in this file — they
+    /// are **not** sourced from a deployed bridge or governance contract.
+    /// Before pointing this decoder at a real RPC log, confirm the deployed
+    /// contract's actual event signatures and indexed parameters match, and
+    /// update the constants and the indexed-field counts below accordingly.
+    pub fn from_log(
+        topics: &[KeccakHash],
+        data: &[u8],
+    ) -> Result<Self, Error> {
+        let signature = topics.first().ok_or(Error::MissingTopic)?;
+
+        if *signature == event_signature(TRANSFERS_TO_NAMADA_SIG) {
+            let [nonce, transfers] =
+                decode_fields(TRANSFERS_TO_NAMADA_SIG, topics, 1, data)?;
+            Ok(EthereumEvent::TransfersToNamada {
+                nonce: uint_from_token(nonce, "nonce")?,
+                transfers: decode_batch(transfers, "transfers")?,
+            })
+        } else if *signature == event_signature(TRANSFERS_TO_ETHEREUM_SIG) {
+            let [nonce, transfers] =
+                decode_fields(TRANSFERS_TO_ETHEREUM_SIG, topics, 1, data)?;
+            Ok(EthereumEvent::TransfersToEthereum {
+                nonce: uint_from_token(nonce, "nonce")?,
+                transfers: decode_batch(transfers, "transfers")?,
+            })
+        } else if *signature == event_signature(VALIDATOR_SET_UPDATE_SIG) {
+            let [nonce, bridge, governance] = decode_fields(
+                VALIDATOR_SET_UPDATE_SIG,
+                topics,
+                1,
+                data,
+            )?;
+            Ok(EthereumEvent::ValidatorSetUpdate {
+                nonce: uint_from_token(nonce, "nonce")?,
+                bridge_validator_hash: keccak_from_token(
+                    bridge,
+                    "bridge_validator_hash",
+                )?,
+                governance_validator_hash: keccak_from_token(
+                    governance,
+                    "governance_validator_hash",
+                )?,
+            })
+        } else if *signature == event_signature(NEW_CONTRACT_SIG) {
+            // neither field is a `nonce`, so nothing here is indexed
+            let [name, address] =
+                decode_fields(NEW_CONTRACT_SIG, topics, 0, data)?;
+            Ok(EthereumEvent::NewContract {
+                name: string_from_token(name, "name")?,
+                address: address_from_token(address, "address")?,
+            })
+        } else if *signature == event_signature(UPDATE_BRIDGE_WHITELIST_SIG) {
+            let [nonce, whitelist] = decode_fields(
+                UPDATE_BRIDGE_WHITELIST_SIG,
+                topics,
+                1,
+                data,
+            )?;
+            Ok(EthereumEvent::UpdateBridgeWhitelist {
+                nonce: uint_from_token(nonce, "nonce")?,
+                whitelist: decode_batch(whitelist, "whitelist")?,
+            })
+        } else {
+            Err(Error::UnknownEvent(signature.clone()))
+        }
+    }
+}
+
+impl EthereumEvent {
+    /// Validate a transfer batch against the active bridge policy before the
+    /// ledger applies it.
+    ///
+    /// Three invariants are enforced: the event `nonce` must equal the
+    /// `expected_nonce` (replay protection), every transferred `asset` must
+    /// appear in the active [`TokenWhitelist`], and the running per-token sum
+    /// of `amount`s must not exceed that token's `cap`. For
+    /// [`EthereumEvent::TransfersToEthereum`] the fee `gas_amount` of every
+    /// transfer must be non-zero and the aggregate must not overflow. The
+    /// `gas_payer` is a non-optional [`Address`] and so is structurally always
+    /// present; there is nothing further to validate about it here. Calling
+    /// this on a non-transfer variant is a programming error and returns
+    /// [`ValidationError::NotATransferBatch`].
+    pub fn validate(
+        &self,
+        whitelist: &[TokenWhitelist],
+        expected_nonce: Uint,
+    ) -> Result<(), ValidationError> {
+        match self {
+            EthereumEvent::TransfersToNamada { nonce, transfers } => {
+                check_nonce(nonce, &expected_nonce)?;
+                check_caps(
+                    transfers.iter().map(|t| (t.asset, t.amount)),
+                    whitelist,
+                )
+            }
+            EthereumEvent::TransfersToEthereum { nonce, transfers } => {
+                check_nonce(nonce, &expected_nonce)?;
+                let mut gas_total = Uint::default();
+                for transfer in transfers {
+                    // `gas_payer` is a non-optional field, so only the fee
+                    // amount can be absent in practice
+                    if transfer.gas_amount == Amount::from(0) {
+                        return Err(ValidationError::MissingGas);
+                    }
+                    gas_total = gas_total
+                        .checked_add(&Uint::from(transfer.gas_amount))
+                        .ok_or(ValidationError::Overflow)?;
+                }
+                check_caps(
+                    transfers.iter().map(|t| (t.asset, t.amount)),
+                    whitelist,
+                )
+            }
+            _ => Err(ValidationError::NotATransferBatch),
+        }
+    }
+}
+
+/// Check that an event `nonce` matches the next expected nonce.
+fn check_nonce(
+    nonce: &Uint,
+    expected: &Uint,
+) -> Result<(), ValidationError> {
+    if nonce != expected {
+        return Err(ValidationError::NonceMismatch {
+            expected: expected.clone(),
+            found: nonce.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Check that every asset is whitelisted and that the running per-token sum
+/// of transferred amounts stays within the token's cap without overflowing.
+fn check_caps(
+    transfers: impl Iterator<Item = (EthAddress, Amount)>,
+    whitelist: &[TokenWhitelist],
+) -> Result<(), ValidationError> {
+    let mut totals: Vec<(EthAddress, Uint)> = Vec::new();
+    for (asset, amount) in transfers {
+        let cap = whitelist
+            .iter()
+            .find(|entry| entry.token == asset)
+            .map(|entry| Uint::from(entry.cap))
+            .ok_or(ValidationError::NotWhitelisted(asset))?;
+        let amount = Uint::from(amount);
+        let total = match totals.iter_mut().find(|(a, _)| *a == asset) {
+            Some((_, running)) => {
+                *running = running
+                    .checked_add(&amount)
+                    .ok_or(ValidationError::Overflow)?;
+                running.clone()
+            }
+            None => {
+                totals.push((asset, amount.clone()));
+                amount
+            }
+        };
+        if total > cap {
+            return Err(ValidationError::CapExceeded(asset));
+        }
+    }
+    Ok(())
+}
+
+/// Errors that can arise while validating a bridge transfer batch.
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    /// The event nonce did not match the expected next nonce.
+    #[error("expected nonce {expected} but the event carried {found}")]
+    NonceMismatch {
+        /// The next nonce the ledger expected.
+        expected: Uint,
+        /// The nonce the event actually carried.
+        found: Uint,
+    },
+    /// A transferred asset is not on the active whitelist.
+    #[error("asset {0} is not whitelisted for bridge transfers")]
+    NotWhitelisted(EthAddress),
+    /// The running sum of transfers for a token exceeds its cap.
+    #[error("transfers for asset {0} exceed the whitelisted cap")]
+    CapExceeded(EthAddress),
+    /// A transfer to Ethereum carried no fee.
+    #[error("transfer to Ethereum is missing a gas fee")]
+    MissingGas,
+    /// A running sum overflowed the 256-bit range.
+    #[error("transfer amount sum overflowed")]
+    Overflow,
+    /// `validate` was called on a non-transfer event.
+    #[error("event is not a transfer batch")]
+    NotATransferBatch,
+}
+
+/// Errors that can arise while ABI-decoding an [`EthereumEvent`] from a log.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The log's first topic matched no known event signature.
+    #[error("unrecognized Ethereum event signature {0:?}")]
+    UnknownEvent(KeccakHash),
+    /// The log lacks a topic the event needs: either its signature topic or
+    /// the topic of an indexed parameter.
+    #[error("Ethereum log is missing a required topic")]
+    MissingTopic,
+    /// The `ethabi` decoder rejected the `data` payload.
+    #[error("failed to ABI-decode event data: {0}")]
+    Abi(#[from] ethabi::Error),
+    /// A decoded value was not the ABI token kind the field expected.
+    #[error("unexpected ABI token for field `{0}`")]
+    UnexpectedToken(&'static str),
+    /// A numeric field did not fit its Namada representation.
+    #[error("value for field `{0}` is out of range")]
+    OutOfRange(&'static str),
+    /// A Namada [`Address`] string could not be parsed.
+    #[error("invalid Namada address for field `{0}`")]
+    InvalidAddress(&'static str),
+}
+
+/// The canonical ABI signature of each decodable [`EthereumEvent`] variant.
+const TRANSFERS_TO_NAMADA_SIG: &str =
+    "TransferToNamada(uint256,(uint256,address,string)[])";
+const TRANSFERS_TO_ETHEREUM_SIG: &str =
+    "TransferToErc(uint256,(uint256,address,address,uint256,string)[])";
+const VALIDATOR_SET_UPDATE_SIG: &str =
+    "ValidatorSetUpdate(uint256,bytes32,bytes32)";
+const NEW_CONTRACT_SIG: &str = "NewContract(string,address)";
+const UPDATE_BRIDGE_WHITELIST_SIG: &str =
+    "UpdateBridgeWhitelist(uint256,(address,uint256)[])";
+
+/// A value decodable from the ABI `Token`s of an Ethereum log.
+pub trait Decode: Sized {
+    /// Map the tuple `Token`s of a single batch entry onto `Self`.
+    fn decode(tokens: &[Token]) -> Result<Self, Error>;
+}
+
+/// The `keccak256` hash of an event signature, i.e. the value a contract
+/// emits as the log's first topic.
+fn event_signature(signature: &str) -> KeccakHash {
+    KeccakHash(keccak256(signature.as_bytes()))
+}
+
+/// ABI-decode the fields of an event against the parameter list embedded in
+/// `signature`, returning exactly `N` tokens in declaration order.
+///
+/// The first `indexed` parameters are each decoded from their own topic
+/// (`topics[1]`, `topics[2]`, ...), matching how Solidity stores a
+/// value-typed `indexed` parameter directly in a topic word. The remaining
+/// parameters are decoded together from `data`, matching how non-indexed
+/// parameters are ABI-encoded into the log's data payload.
+fn decode_fields<const N: usize>(
+    signature: &str,
+    topics: &[KeccakHash],
+    indexed: usize,
+    data: &[u8],
+) -> Result<[Token; N], Error> {
+    let params = parse_param_types(signature);
+    let (indexed_params, data_params) = params.split_at(indexed);
+
+    let indexed_topics = topics.get(1..).unwrap_or_default();
+    if indexed_topics.len() < indexed {
+        return Err(Error::MissingTopic);
+    }
+    let mut tokens = Vec::with_capacity(N);
+    for (param, topic) in indexed_params.iter().zip(indexed_topics) {
+        let decoded = ethabi::decode(std::slice::from_ref(param), &topic.0)?;
+        tokens.extend(decoded);
+    }
+    tokens.extend(ethabi::decode(data_params, data)?);
+
+    tokens
+        .try_into()
+        .map_err(|_| Error::UnexpectedToken("event arity"))
+}
+
+/// Parse the comma-separated parameter list out of an event signature string
+/// into the [`ParamType`]s `ethabi` expects.
+fn parse_param_types(signature: &str) -> Vec<ParamType> {
+    let start = signature.find('(').expect("signature has a parameter list");
+    // the parameter list without the surrounding parentheses
+    let params = &signature[start + 1..signature.len() - 1];
+    split_top_level(params)
+        .into_iter()
+        .map(parse_param_type)
+        .collect()
+}
+
+/// Split a parameter list on commas that are not nested inside a tuple.
+fn split_top_level(params: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut last = 0usize;
+    for (i, c) in params.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&params[last..i]);
+                last = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !params.is_empty() {
+        parts.push(&params[last..]);
+    }
+    parts
+}
+
+/// Parse a single ABI type, handling the `[]` array suffix and nested tuples.
+fn parse_param_type(ty: &str) -> ParamType {
+    let ty = ty.trim();
+    if let Some(inner) = ty.strip_suffix("[]") {
+        return ParamType::Array(Box::new(parse_param_type(inner)));
+    }
+    if let Some(inner) = ty.strip_prefix('(') {
+        let inner = inner.strip_suffix(')').expect("balanced tuple");
+        return ParamType::Tuple(
+            split_top_level(inner).into_iter().map(parse_param_type).collect(),
+        );
+    }
+    match ty {
+        "uint256" => ParamType::Uint(256),
+        "address" => ParamType::Address,
+        "string" => ParamType::String,
+        "bytes32" => ParamType::FixedBytes(32),
+        other => panic!("unsupported ABI type in event signature: {}", other),
+    }
+}
+
+/// Decode a `Token::Array` of tuples into a `Vec` of [`Decode`] values.
+fn decode_batch<T: Decode>(
+    token: Token,
+    field: &'static str,
+) -> Result<Vec<T>, Error> {
+    match token {
+        Token::Array(entries) => entries
+            .into_iter()
+            .map(|entry| match entry {
+                Token::Tuple(tokens) => T::decode(&tokens),
+                _ => Err(Error::UnexpectedToken(field)),
+            })
+            .collect(),
+        _ => Err(Error::UnexpectedToken(field)),
+    }
+}
+
+fn uint_from_token(token: Token, field: &'static str) -> Result<Uint, Error> {
+    match token {
+        Token::Uint(value) => Ok(Uint::from(value)),
+        _ => Err(Error::UnexpectedToken(field)),
+    }
+}
+
+fn amount_from_token(
+    token: Token,
+    field: &'static str,
+) -> Result<Amount, Error> {
+    match token {
+        Token::Uint(value) => {
+            if value.bits() > 64 {
+                return Err(Error::OutOfRange(field));
+            }
+            Ok(Amount::from(value.low_u64()))
+        }
+        _ => Err(Error::UnexpectedToken(field)),
+    }
+}
+
+fn address_from_token(
+    token: Token,
+    field: &'static str,
+) -> Result<EthAddress, Error> {
+    match token {
+        Token::Address(address) => Ok(EthAddress(address.into())),
+        _ => Err(Error::UnexpectedToken(field)),
+    }
+}
+
+fn string_from_token(
+    token: Token,
+    field: &'static str,
+) -> Result<String, Error> {
+    match token {
+        Token::String(value) => Ok(value),
+        _ => Err(Error::UnexpectedToken(field)),
+    }
+}
+
+fn namada_address_from_token(
+    token: Token,
+    field: &'static str,
+) -> Result<Address, Error> {
+    let raw = string_from_token(token, field)?;
+    Address::decode(&raw).map_err(|_| Error::InvalidAddress(field))
+}
+
+fn keccak_from_token(
+    token: Token,
+    field: &'static str,
+) -> Result<KeccakHash, Error> {
+    match token {
+        Token::FixedBytes(bytes) if bytes.len() == 32 => {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&bytes);
+            Ok(KeccakHash(hash))
+        }
+        _ => Err(Error::UnexpectedToken(field)),
+    }
+}
+
+impl Decode for TransferToNamada {
+    fn decode(tokens: &[Token]) -> Result<Self, Error> {
+        let [amount, asset, receiver] = take_tuple(tokens, "TransferToNamada")?;
+        Ok(TransferToNamada {
+            amount: amount_from_token(amount, "amount")?,
+            asset: address_from_token(asset, "asset")?,
+            receiver: namada_address_from_token(receiver, "receiver")?,
+        })
+    }
+}
+
+impl Decode for TransferToEthereum {
+    fn decode(tokens: &[Token]) -> Result<Self, Error> {
+        let [amount, asset, receiver, gas_amount, gas_payer] =
+            take_tuple(tokens, "TransferToEthereum")?;
+        Ok(TransferToEthereum {
+            amount: amount_from_token(amount, "amount")?,
+            asset: address_from_token(asset, "asset")?,
+            receiver: address_from_token(receiver, "receiver")?,
+            gas_amount: amount_from_token(gas_amount, "gas_amount")?,
+            gas_payer: namada_address_from_token(gas_payer, "gas_payer")?,
+        })
+    }
+}
+
+impl Decode for TokenWhitelist {
+    fn decode(tokens: &[Token]) -> Result<Self, Error> {
+        let [token, cap] = take_tuple(tokens, "TokenWhitelist")?;
+        Ok(TokenWhitelist {
+            token: address_from_token(token, "token")?,
+            cap: amount_from_token(cap, "cap")?,
+        })
+    }
+}
+
+/// Move the fields of a batch entry tuple into a fixed-size array, erroring on
+/// an unexpected arity.
+fn take_tuple<const N: usize>(
+    tokens: &[Token],
+    field: &'static str,
+) -> Result<[Token; N], Error> {
+    <[Token; N]>::try_from(tokens.to_vec())
+        .map_err(|_| Error::UnexpectedToken(field))
 }
 
 /// An event transferring some kind of value from Ethereum to Namada
@@ -300,7 +982,6 @@ pub struct TransferToEthereum {
     BorshDeserialize,
     BorshSchema,
 )]
-#[allow(dead_code)]
 pub struct TokenWhitelist {
     /// Address of Ethereum smart contract issuing token
     pub token: EthAddress,
@@ -314,6 +995,252 @@ pub mod tests {
 
     use super::*;
 
+    fn dai_h160() -> ethabi::ethereum_types::Address {
+        ethabi::ethereum_types::Address::from(&testing::DAI_ERC20_ETH_ADDRESS.0)
+    }
+
+    /// The raw 32-byte topic word Solidity stores for an `indexed uint256`,
+    /// i.e. the value's big-endian encoding with no ABI offset/length
+    /// framing. This is built by hand, not via `ethabi::encode`, so the
+    /// decoder is exercised against the actual wire format rather than
+    /// against `ethabi`'s encoder.
+    fn indexed_uint_topic(value: u64) -> KeccakHash {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        KeccakHash(word)
+    }
+
+    #[test]
+    fn test_from_log_transfers_to_namada_roundtrip() {
+        let receiver =
+            crate::types::address::testing::established_address_1();
+        // only the non-indexed `transfers` batch is ABI-encoded into `data`;
+        // the indexed `nonce` lives in `topics[1]`
+        let data = ethabi::encode(&[Token::Array(vec![Token::Tuple(vec![
+            Token::Uint(1_000u64.into()),
+            Token::Address(dai_h160()),
+            Token::String(receiver.to_string()),
+        ])])]);
+        let topics = vec![
+            event_signature(TRANSFERS_TO_NAMADA_SIG),
+            indexed_uint_topic(7),
+        ];
+
+        let event = EthereumEvent::from_log(&topics, &data).unwrap();
+        assert_eq!(
+            event,
+            EthereumEvent::TransfersToNamada {
+                nonce: Uint::from(7u64),
+                transfers: vec![TransferToNamada {
+                    amount: Amount::from(1_000),
+                    asset: testing::DAI_ERC20_ETH_ADDRESS,
+                    receiver,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_log_transfers_to_ethereum_roundtrip() {
+        let gas_payer =
+            crate::types::address::testing::established_address_1();
+        let data = ethabi::encode(&[Token::Array(vec![Token::Tuple(vec![
+            Token::Uint(500u64.into()),
+            Token::Address(dai_h160()),
+            Token::Address(dai_h160()),
+            Token::Uint(10u64.into()),
+            Token::String(gas_payer.to_string()),
+        ])])]);
+        let topics = vec![
+            event_signature(TRANSFERS_TO_ETHEREUM_SIG),
+            indexed_uint_topic(3),
+        ];
+
+        let event = EthereumEvent::from_log(&topics, &data).unwrap();
+        assert_eq!(
+            event,
+            EthereumEvent::TransfersToEthereum {
+                nonce: Uint::from(3u64),
+                transfers: vec![TransferToEthereum {
+                    amount: Amount::from(500),
+                    asset: testing::DAI_ERC20_ETH_ADDRESS,
+                    receiver: testing::DAI_ERC20_ETH_ADDRESS,
+                    gas_amount: Amount::from(10),
+                    gas_payer,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_log_rejects_data_only_indexed_nonce() {
+        // a log that packs the nonce into `data` instead of an indexed topic
+        // (the old, incorrect layout) must not silently decode
+        let receiver =
+            crate::types::address::testing::established_address_1();
+        let data = ethabi::encode(&[
+            Token::Uint(7u64.into()),
+            Token::Array(vec![Token::Tuple(vec![
+                Token::Uint(1_000u64.into()),
+                Token::Address(dai_h160()),
+                Token::String(receiver.to_string()),
+            ])]),
+        ]);
+        let topics = vec![event_signature(TRANSFERS_TO_NAMADA_SIG)];
+
+        assert!(EthereumEvent::from_log(&topics, &data).is_err());
+    }
+
+    #[test]
+    fn test_from_log_unknown_signature() {
+        let data = ethabi::encode(&[Token::Uint(1u64.into())]);
+        let topics = vec![KeccakHash([0xab; 32])];
+
+        assert!(matches!(
+            EthereumEvent::from_log(&topics, &data),
+            Err(Error::UnknownEvent(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_log_missing_topic() {
+        assert!(matches!(
+            EthereumEvent::from_log(&[], &[]),
+            Err(Error::MissingTopic)
+        ));
+    }
+
+    fn dai_whitelist(cap: u64) -> Vec<TokenWhitelist> {
+        vec![TokenWhitelist {
+            token: testing::DAI_ERC20_ETH_ADDRESS,
+            cap: Amount::from(cap),
+        }]
+    }
+
+    #[test]
+    fn test_validate_transfers_to_namada_ok() {
+        let event = testing::arbitrary_single_transfer(
+            Uint::from(5u64),
+            crate::types::address::testing::established_address_1(),
+        );
+
+        assert!(event.validate(&dai_whitelist(10_000), Uint::from(5u64)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_nonce_replay() {
+        let event = testing::arbitrary_single_transfer(
+            Uint::from(4u64),
+            crate::types::address::testing::established_address_1(),
+        );
+
+        let result = event.validate(&dai_whitelist(10_000), Uint::from(5u64));
+        assert!(matches!(
+            result,
+            Err(ValidationError::NonceMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_unwhitelisted_asset() {
+        let event = testing::arbitrary_single_transfer(
+            Uint::from(5u64),
+            crate::types::address::testing::established_address_1(),
+        );
+
+        let whitelist = vec![TokenWhitelist {
+            token: testing::USDC_ERC20_ETH_ADDRESS,
+            cap: Amount::from(10_000),
+        }];
+        assert!(matches!(
+            event.validate(&whitelist, Uint::from(5u64)),
+            Err(ValidationError::NotWhitelisted(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_enforces_cap() {
+        let event = testing::arbitrary_single_transfer(
+            Uint::from(5u64),
+            crate::types::address::testing::established_address_1(),
+        );
+
+        // the arbitrary transfer moves 1_000 of the asset
+        assert!(matches!(
+            event.validate(&dai_whitelist(999), Uint::from(5u64)),
+            Err(ValidationError::CapExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_enforces_cap_across_limb_boundary() {
+        // two transfers whose running sum crosses the 64-bit limb boundary
+        // must still be compared numerically against the cap, not by the
+        // (incorrect) lexicographic ordering of the underlying limb array
+        let event = EthereumEvent::TransfersToNamada {
+            nonce: Uint::from(5u64),
+            transfers: vec![
+                TransferToNamada {
+                    amount: Amount::from(u64::MAX),
+                    asset: testing::DAI_ERC20_ETH_ADDRESS,
+                    receiver: crate::types::address::testing::established_address_1(),
+                },
+                TransferToNamada {
+                    amount: Amount::from(1),
+                    asset: testing::DAI_ERC20_ETH_ADDRESS,
+                    receiver: crate::types::address::testing::established_address_1(),
+                },
+            ],
+        };
+
+        assert!(matches!(
+            event.validate(&dai_whitelist(u64::MAX), Uint::from(5u64)),
+            Err(ValidationError::CapExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_uint_checked_arithmetic() {
+        let one = Uint::from(1u64);
+        let max: Uint = ethUint::MAX.into();
+
+        assert_eq!(one.checked_add(&one), Some(Uint::from(2u64)));
+        assert_eq!(max.checked_add(&one), None);
+        assert_eq!(one.checked_sub(&Uint::from(2u64)), None);
+        assert_eq!(max.saturating_add(&one), max);
+        assert_eq!(Uint::from(41u64).increment(), Some(Uint::from(42u64)));
+        assert_eq!(max.increment(), None);
+    }
+
+    #[test]
+    fn test_uint_ord_is_numeric() {
+        // `Uint` is backed by a little-endian `[u64; 4]`, so comparing it
+        // lexicographically (as `#[derive(Ord)]` would) compares the wrong
+        // limb first. A value that only differs above the low 64 bits must
+        // still compare greater numerically.
+        let max_low_limb = Uint::from(u64::MAX);
+        let crossed_boundary = max_low_limb.increment().unwrap();
+        assert!(crossed_boundary > max_low_limb);
+        assert!(max_low_limb < crossed_boundary);
+    }
+
+    #[test]
+    fn test_uint_display_fromstr_roundtrip() {
+        let value = Uint::from(123_456_789u64);
+        assert_eq!(value.to_string(), "123456789");
+        assert_eq!(Uint::from_str("123456789").unwrap(), value);
+    }
+
+    #[test]
+    fn test_uint_amount_conversion() {
+        let amount = Amount::from(1_000);
+        assert_eq!(Uint::from(amount), Uint::from(1_000u64));
+        assert_eq!(Amount::try_from(Uint::from(1_000u64)).unwrap(), amount);
+        // a value beyond u64::MAX cannot be an Amount
+        let big: Uint = ethUint::MAX.into();
+        assert!(Amount::try_from(big).is_err());
+    }
+
     #[test]
     fn test_eth_address_to_canonical() {
         let canonical = testing::DAI_ERC20_ETH_ADDRESS.to_canonical();
@@ -342,6 +1269,93 @@ pub mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_eth_address_to_checksum() {
+        let checksummed = testing::DAI_ERC20_ETH_ADDRESS.to_checksum();
+
+        assert_eq!(
+            checksummed,
+            testing::DAI_ERC20_ETH_ADDRESS_CHECKSUMMED,
+        );
+        assert_eq!(
+            testing::USDC_ERC20_ETH_ADDRESS.to_checksum(),
+            testing::USDC_ERC20_ETH_ADDRESS_CHECKSUMMED,
+        );
+    }
+
+    #[test]
+    fn test_eth_address_from_checksum_str() {
+        // a correctly checksummed address round-trips
+        let addr = EthAddress::from_checksum_str(
+            testing::DAI_ERC20_ETH_ADDRESS_CHECKSUMMED,
+        )
+        .unwrap();
+        assert_eq!(testing::DAI_ERC20_ETH_ADDRESS, addr);
+
+        // all lower case and all upper case carry no checksum
+        assert!(
+            EthAddress::from_checksum_str(
+                &testing::DAI_ERC20_ETH_ADDRESS_CHECKSUMMED.to_lowercase()
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_eth_address_from_checksum_str_error() {
+        // flip the case of a single letter to corrupt the checksum
+        let mut bad =
+            testing::DAI_ERC20_ETH_ADDRESS_CHECKSUMMED.to_string();
+        // 'B' at index 3 (first hex letter after "0x6") is uppercase in the
+        // valid checksum; lowercasing it breaks the checksum
+        bad.replace_range(3..4, "b");
+
+        assert!(EthAddress::from_checksum_str(&bad).is_err());
+    }
+
+    #[test]
+    fn test_eth_address_recover_roundtrip() {
+        use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+        let secp = Secp256k1::new();
+        let secret = SecretKey::from_slice(&[0x11; 32]).unwrap();
+        let public = PublicKey::from_secret_key(&secp, &secret);
+
+        // the address is the last 20 bytes of the keccak256 of the 64-byte
+        // uncompressed public key
+        let uncompressed = public.serialize_uncompressed();
+        let hash = keccak256(&uncompressed[1..]);
+        let mut expected = [0u8; 20];
+        expected.copy_from_slice(&hash[12..]);
+        let expected = EthAddress(expected);
+
+        // sign the raw challenge directly, as a standard wallet's
+        // `personal_sign` does, rather than a pre-hashed digest of it
+        let msg = b"bind my Namada address";
+        let prefix =
+            format!("\x19Ethereum Signed Message:\n{}", msg.len());
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(prefix.as_bytes());
+        preimage.extend_from_slice(msg);
+        let digest = Message::from_slice(&keccak256(&preimage)).unwrap();
+
+        let recoverable = secp.sign_ecdsa_recoverable(&digest, &secret);
+        let (recovery_id, compact) = recoverable.serialize_compact();
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(&compact);
+        // wallets offset `v` by 27
+        signature[64] = recovery_id.to_i32() as u8 + 27;
+
+        assert_eq!(EthAddress::recover(msg, &signature).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_eth_address_recover_rejects_malformed() {
+        // an all-zero signature has no valid `r`/`s` and cannot recover
+        let result = EthAddress::recover(b"challenge", &[0u8; 65]);
+        assert!(result.is_err());
+    }
+
     /// Test that serde correct serializes EthAddress types to/from lowercase
     /// hex encodings
     #[test]